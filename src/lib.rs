@@ -31,6 +31,16 @@ pub fn say_hello(who: Option<String>, context: ServiceContext) -> Result<String,
     }
 }
 
+/// Builds the event handlers for this blueprint's jobs.
+///
+/// Register the result with a `BlueprintRunner` via `.job(...)`, alongside any jobs of your own.
+pub async fn build_handlers(
+    env: &sdk::config::StdGadgetConfiguration,
+    context: ServiceContext,
+) -> Result<SayHelloEventHandler, sdk::Error> {
+    SayHelloEventHandler::new(env, context).await
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
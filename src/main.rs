@@ -12,8 +12,8 @@ async fn main() -> Result<()> {
         config: env.clone(),
     };
 
-    // Create the event handler from the job
-    let say_hello_job = blueprint::SayHelloEventHandler::new(&env, context).await?;
+    // Create the event handlers from the jobs
+    let say_hello_job = blueprint::build_handlers(&env, context).await?;
 
     tracing::info!("Starting the event watcher ...");
     let tangle_config = TangleConfig::default();